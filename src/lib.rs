@@ -0,0 +1,648 @@
+// This crate consistently favors explicit `return` statements and `.clone()`
+// on `Copy` types for readability at call sites; silence the corresponding
+// lints rather than fight the style.
+#![allow(clippy::needless_return, clippy::clone_on_copy)]
+
+use std::collections::{HashSet, VecDeque};
+use std::iter;
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crossbeam_deque::{Injector, Stealer, Worker};
+use futures::channel::mpsc;
+use futures::{SinkExt, Stream};
+
+/*
+State
+*/
+
+/// A 9x9 sudoku board, together with the remaining-candidate bookkeeping that
+/// constraint propagation and branching need. Cheap to `Copy`, so the solver
+/// clones it freely when branching.
+#[derive(Copy, Clone)]
+pub struct State {
+    pub unsolved_squares: i8,
+    pub board: [[Square; 9]; 9],
+}
+
+/// One board square: its known solution (`0` if still unsolved) and, while
+/// unsolved, which of 1-9 remain possible.
+#[derive(Copy, Clone)]
+pub struct Square {
+    pub solution: i8,
+    pub num_possible: i8,
+    pub possible: [bool; 9],
+}
+
+impl State {
+    // Applies solution to the square at offset row, col.
+    // Removes solution as a possibility from the square's peers.
+    // Returns true if the board remains valid after the solution was applied, false otherwise.
+    fn propagate_solution(&mut self, target_row: usize, target_col: usize, solution: i8) -> bool {
+        assert!(target_row < 9);
+        assert!(target_col < 9);
+        assert!(solution >= 1);
+        assert!(solution <= 9);
+        assert!(self.unsolved_squares > 0);
+        // A peer may have already ruled `solution` out for this square (e.g. a
+        // conflicting given elsewhere in its row/column/box); assigning it
+        // anyway would contradict that, so report the board invalid instead.
+        if !self.board[target_row][target_col].possible[(solution - 1) as usize] {
+            return false;
+        }
+        // Set the solution.
+        self.unsolved_squares -= 1;
+        self.board[target_row][target_col].solution = solution;
+        // Clear all possibilities for the target square.
+        self.board[target_row][target_col].num_possible = 0;
+        for i in 0..9 {
+            self.board[target_row][target_col].possible[i] = false;
+        }
+        // Clear option across the row.
+        for j in 0..9 {
+            if !self.remove_possibility(target_row, j, solution) {
+                return false;
+            }
+        }
+        // Clear option up and down the col.
+        for i in 0..9 {
+            if !self.remove_possibility(i, target_col, solution) {
+                return false;
+            }
+        }
+        // Clear option throughout the sub-board.
+        let sub_board_row = State::sub_board_offset(target_row);
+        let sub_board_col = State::sub_board_offset(target_col);
+        for i in 0..3 {
+            for j in 0..3 {
+                let row = sub_board_row * 3 + i;
+                let col = sub_board_col * 3 + j;
+                if !self.remove_possibility(row, col, solution) {
+                    return false;
+                }
+            }
+        }
+        return true;
+    }
+
+    // Removes the possibility from the specified square.
+    // Returns whether the square remains valid/viable afterward.
+    fn remove_possibility(&mut self, row: usize, col: usize, solution: i8) -> bool {
+        assert!(row < 9);
+        assert!(col < 9);
+        assert!(solution > 0);
+        assert!(solution <= 9);
+        let peer_cell = &mut self.board[row][col];
+        let possibility_idx = (solution - 1) as usize;
+        if peer_cell.possible[possibility_idx] {
+            peer_cell.num_possible -= 1;
+            peer_cell.possible[possibility_idx] = false;
+        }
+        return peer_cell.is_valid();
+    }
+
+    fn sub_board_offset(index: usize) -> usize {
+        // use truncating integer division to get the sub-board.
+        return index / 3;
+    }
+
+    // Applies `solution` at `row, col`, then runs constraint propagation out to a
+    // fixpoint: naked singles (a square with exactly one remaining candidate) and
+    // hidden singles (a value with exactly one remaining position in some row,
+    // column, or box) are assigned immediately, and each assignment re-queues its
+    // own peers for the same checks, until nothing more is forced.
+    // Returns false as soon as the board is proven unsolvable.
+    fn assign_and_propagate(&mut self, row: usize, col: usize, solution: i8) -> bool {
+        if !self.propagate_solution(row, col, solution) {
+            return false;
+        }
+        return self.propagate_to_fixpoint(row, col);
+    }
+
+    // Worklist-driven fixpoint, seeded by the peers of the square that was just
+    // assigned at `seed_row, seed_col`. Mirrors a dataflow fixpoint: each dequeued
+    // square is rechecked for naked and hidden singles, and any forced assignment
+    // pushes its peers back onto the worklist.
+    fn propagate_to_fixpoint(&mut self, seed_row: usize, seed_col: usize) -> bool {
+        let mut worklist = Worklist::new();
+        self.enqueue_peers(seed_row, seed_col, &mut worklist);
+        while let Some((row, col)) = worklist.pop() {
+            if !self.assign_naked_single(row, col, &mut worklist) {
+                return false;
+            }
+            if !self.assign_hidden_singles(row, col, &mut worklist) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    // If `row, col` is unsolved but has exactly one remaining candidate, assigns
+    // it and enqueues its peers for further propagation.
+    fn assign_naked_single(&mut self, row: usize, col: usize, worklist: &mut Worklist) -> bool {
+        let square = &self.board[row][col];
+        if square.solution > 0 || square.num_possible != 1 {
+            return true;
+        }
+        let solution = (square.possible.iter().position(|&p| p).unwrap() + 1) as i8;
+        if !self.propagate_solution(row, col, solution) {
+            return false;
+        }
+        self.enqueue_peers(row, col, worklist);
+        return true;
+    }
+
+    // Checks the row, column, and box containing `row, col` for hidden singles: a
+    // value with exactly one remaining candidate position within the unit.
+    fn assign_hidden_singles(&mut self, row: usize, col: usize, worklist: &mut Worklist) -> bool {
+        let sub_board_row = State::sub_board_offset(row);
+        let sub_board_col = State::sub_board_offset(col);
+        let units: [Vec<(usize, usize)>; 3] = [
+            (0..9).map(|c| (row, c)).collect(),
+            (0..9).map(|r| (r, col)).collect(),
+            (0..3)
+                .flat_map(|i| (0..3).map(move |j| (sub_board_row * 3 + i, sub_board_col * 3 + j)))
+                .collect(),
+        ];
+        for unit in units.iter() {
+            for value in 1..=9i8 {
+                let possibility_idx = (value - 1) as usize;
+                let mut candidate_positions = unit.iter().filter(|&&(r, c)| {
+                    self.board[r][c].solution == 0 && self.board[r][c].possible[possibility_idx]
+                });
+                let first = candidate_positions.next();
+                if let Some(&(target_row, target_col)) = first {
+                    if candidate_positions.next().is_none() {
+                        // Exactly one candidate position for `value` in this unit.
+                        if !self.propagate_solution(target_row, target_col, value) {
+                            return false;
+                        }
+                        self.enqueue_peers(target_row, target_col, worklist);
+                    }
+                }
+            }
+        }
+        return true;
+    }
+
+    // Enqueues every peer (row, column, and box) of `row, col` for re-checking.
+    fn enqueue_peers(&self, row: usize, col: usize, worklist: &mut Worklist) {
+        for j in 0..9 {
+            worklist.push(row, j);
+        }
+        for i in 0..9 {
+            worklist.push(i, col);
+        }
+        let sub_board_row = State::sub_board_offset(row);
+        let sub_board_col = State::sub_board_offset(col);
+        for i in 0..3 {
+            for j in 0..3 {
+                worklist.push(sub_board_row * 3 + i, sub_board_col * 3 + j);
+            }
+        }
+    }
+}
+
+impl Square {
+    fn is_valid(&self) -> bool {
+        // To be valid, squares need a solution or candidate solutions.
+        return self.solution > 0 || self.num_possible > 0;
+    }
+}
+
+// A FIFO queue of squares awaiting a naked/hidden single recheck, deduplicated
+// so the same square is never pending twice at once. Peers overlap heavily (a
+// square's row, column, and box all share cells), so without this, a single
+// assignment's `enqueue_peers` call alone pushes many duplicate entries, and
+// propagate_to_fixpoint ends up rechecking the same squares over and over.
+struct Worklist {
+    queue: VecDeque<(usize, usize)>,
+    pending: HashSet<(usize, usize)>,
+}
+
+impl Worklist {
+    fn new() -> Worklist {
+        return Worklist {
+            queue: VecDeque::new(),
+            pending: HashSet::new(),
+        };
+    }
+
+    fn push(&mut self, row: usize, col: usize) {
+        if self.pending.insert((row, col)) {
+            self.queue.push_back((row, col));
+        }
+    }
+
+    fn pop(&mut self) -> Option<(usize, usize)> {
+        let next = self.queue.pop_front();
+        if let Some(square) = next {
+            self.pending.remove(&square);
+        }
+        return next;
+    }
+}
+
+/*
+Solver
+*/
+
+// Per-search bookkeeping shared by every task descended from one `solve_stream`
+// call. Kept separate from `ThreadPool` so the pool's workers and deques are
+// created exactly once at startup and reused across searches, rather than
+// spun up and torn down per puzzle.
+struct Job {
+    cancelled: Arc<AtomicBool>,
+    // Starts at 1 (the seed task) and only reaches zero once every task
+    // descended from it has been fully expanded into either more tasks or a
+    // dead end, at which point no more work for this job can ever appear.
+    outstanding_tasks: AtomicIsize,
+    solution_tx: mpsc::Sender<State>,
+    // An extra clone of `solution_tx`, closed the moment `outstanding_tasks`
+    // reaches zero so the stream ends there instead of staying open for as
+    // long as some caller happens to hold the `Job` alive.
+    keep_alive: Mutex<Option<mpsc::Sender<State>>>,
+}
+
+impl Job {
+    // Applies `net_task_change` to `outstanding_tasks` (e.g. -1 for a dead end
+    // or a solution, `tasks_pushed - 1` for a branch) and closes the solution
+    // stream if that was the last outstanding task.
+    fn account_for_tasks(&self, net_task_change: isize) {
+        let remaining = self.outstanding_tasks.fetch_add(net_task_change, Ordering::SeqCst)
+            + net_task_change;
+        if remaining == 0 {
+            // Closing (not merely dropping) this clone ends the stream for the
+            // receiver, even though `solution_tx` itself is still alive.
+            if let Some(mut keep_alive) = self.keep_alive.lock().unwrap().take() {
+                keep_alive.close_channel();
+            }
+        }
+    }
+}
+
+// A unit of search: a board awaiting its next branch decision, tagged with the
+// job it belongs to so a shared pool of workers can service many searches.
+type Task = (State, Arc<Job>);
+
+/// A handle that lets a consumer cancel an in-flight parallel search. Cancelling
+/// is best-effort (the flag is only observed between tasks) and may be called
+/// from any thread, including from inside a callback driving the solution stream.
+#[derive(Clone)]
+pub struct AbortHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl AbortHandle {
+    pub fn abort(&self) {
+        // Cancellations are best effort, so use `Ordering::Relaxed`.
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// A work-stealing thread pool dedicated to the sudoku search. `num_threads`
+/// workers are spawned once, at startup, and run for the rest of the process
+/// (dropping the pool does not stop them);
+/// `solve_stream` hands them a new job to chew on rather than spinning up a new
+/// set of threads per search, so running many searches back to back (e.g.
+/// `--batch` mode) costs no more threads than running one.
+pub struct ThreadPool {
+    injector: Arc<Injector<Task>>,
+    num_workers: usize,
+}
+
+impl ThreadPool {
+    pub fn new(num_threads: usize) -> ThreadPool {
+        assert!(num_threads > 0);
+        let injector = Arc::new(Injector::<Task>::new());
+        let locals: Vec<Worker<Task>> = (0..num_threads).map(|_| Worker::new_lifo()).collect();
+        let stealers: Arc<Vec<Stealer<Task>>> =
+            Arc::new(locals.iter().map(Worker::stealer).collect());
+        for local in locals {
+            let injector = injector.clone();
+            let stealers = stealers.clone();
+            thread::spawn(move || worker_loop(local, injector, stealers));
+        }
+        return ThreadPool {
+            injector,
+            num_workers: num_threads,
+        };
+    }
+
+    /// Searches `state` using this pool and returns a stream that yields every
+    /// solution found, plus a handle the caller can use to cancel the search
+    /// (e.g. after confirming uniqueness by requesting two solutions). Workers
+    /// keep searching for further solutions until either this job is exhausted
+    /// or the handle is aborted, so `solve_stream` is suitable both for
+    /// validation (find any) and enumeration (find all, or find N).
+    pub fn solve_stream(&self, state: State) -> (impl Stream<Item = State>, AbortHandle) {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        // Bounded so a slow (or stalled) consumer applies backpressure to the
+        // search instead of letting solutions pile up unboundedly in memory.
+        let (solution_tx, solution_rx) = mpsc::channel(self.num_workers);
+        let job = Arc::new(Job {
+            cancelled: cancelled.clone(),
+            outstanding_tasks: AtomicIsize::new(1),
+            keep_alive: Mutex::new(Some(solution_tx.clone())),
+            solution_tx,
+        });
+        self.injector.push((state, job));
+        return (solution_rx, AbortHandle { cancelled });
+    }
+}
+
+// The body of one pool worker. Runs for the lifetime of the process, popping
+// (or stealing) tasks from whichever jobs are currently in flight; when none
+// are available it just yields, since the pool itself is never torn down.
+fn worker_loop(local: Worker<Task>, injector: Arc<Injector<Task>>, stealers: Arc<Vec<Stealer<Task>>>) {
+    loop {
+        match find_task(&local, &injector, &stealers) {
+            Some(task) => process_task(task, &local),
+            None => thread::yield_now(),
+        }
+    }
+}
+
+// Pops a task from the worker's own deque, falling back to stealing from the
+// shared injector or another worker's deque.
+fn find_task(
+    local: &Worker<Task>,
+    injector: &Injector<Task>,
+    stealers: &[Stealer<Task>],
+) -> Option<Task> {
+    local.pop().or_else(|| {
+        iter::repeat_with(|| {
+            injector
+                .steal_batch_and_pop(local)
+                .or_else(|| stealers.iter().map(|s| s.steal()).collect())
+        })
+        .find(|s| !s.is_retry())
+        .and_then(|s| s.success())
+    })
+}
+
+// Expands one task to completion: reports a solution onto its job's channel, or
+// picks the most-constrained unsolved square and expands it into child tasks
+// pushed onto `local`. Does nothing but account for the task if its job has
+// been cancelled, so an aborted search drains quickly without being explored.
+fn process_task(task: Task, local: &Worker<Task>) {
+    let (state, job) = task;
+    if job.cancelled.load(Ordering::Relaxed) {
+        job.account_for_tasks(-1);
+        return;
+    }
+    if state.unsolved_squares == 0 {
+        // Block this worker until the consumer has room so a full channel
+        // applies real backpressure instead of silently dropping solutions.
+        // The only failure mode is the consumer having dropped the receiver
+        // (e.g. after aborting), which leaves nothing useful to do either way.
+        let mut solution_tx = job.solution_tx.clone();
+        let _ = futures::executor::block_on(solution_tx.send(state));
+        job.account_for_tasks(-1);
+        return;
+    }
+    // Propagation already rules out any task whose board holds an invalid square,
+    // so there's always an unsolved square left to branch on here.
+    let (row, col) = find_most_constrained_square(&state).unwrap();
+    branch_on_square(state, row, col, &job, local);
+}
+
+// Scans the whole board for the most-constrained unsolved square (fewest
+// remaining candidates, a.k.a. minimum-remaining-values), breaking ties by
+// whichever candidate's row, column, or box has the fewest unsolved squares left.
+// Branching on this square shrinks the branching factor and the number of child
+// tasks handed to the pool, which is why we recompute it fresh at every node
+// instead of walking the board in `{row, col}` order.
+fn find_most_constrained_square(state: &State) -> Option<(usize, usize)> {
+    let mut best: Option<(usize, usize)> = None;
+    let mut best_num_possible = i8::MAX;
+    let mut best_unit_size = usize::MAX;
+    for row in 0..9 {
+        for col in 0..9 {
+            let square = &state.board[row][col];
+            if square.solution > 0 {
+                continue;
+            }
+            let unit_size = most_constrained_unit_size(state, row, col);
+            if square.num_possible < best_num_possible
+                || (square.num_possible == best_num_possible && unit_size < best_unit_size)
+            {
+                best = Some((row, col));
+                best_num_possible = square.num_possible;
+                best_unit_size = unit_size;
+            }
+        }
+    }
+    return best;
+}
+
+// Returns the fewest unsolved squares found across `row, col`'s row, column, and
+// box (its three constraining units).
+fn most_constrained_unit_size(state: &State, row: usize, col: usize) -> usize {
+    let sub_board_row = State::sub_board_offset(row);
+    let sub_board_col = State::sub_board_offset(col);
+    let row_unsolved = (0..9).filter(|&c| state.board[row][c].solution == 0).count();
+    let col_unsolved = (0..9).filter(|&r| state.board[r][col].solution == 0).count();
+    let box_unsolved = (0..3)
+        .flat_map(|i| (0..3).map(move |j| (sub_board_row * 3 + i, sub_board_col * 3 + j)))
+        .filter(|&(r, c)| state.board[r][c].solution == 0)
+        .count();
+    return row_unsolved.min(col_unsolved).min(box_unsolved);
+}
+
+// Tries every candidate solution for `(row, col)`, pushing the resulting board as
+// a new task for each one onto `local`'s deque bottom (LIFO, so a worker keeps
+// exploring its own branch depth-first until it runs dry).
+fn branch_on_square(state: State, row: usize, col: usize, job: &Arc<Job>, local: &Worker<Task>) {
+    let mut tasks_pushed: isize = 0;
+    for sln_idx in 0..9 {
+        if !state.board[row][col].possible[sln_idx] {
+            // Skip invalid possibilities.
+            continue;
+        }
+        // Copy state and try a candidate solution for this square, propagating
+        // any forced moves it implies before handing the branch off as a task.
+        let mut state_copy = state.clone();
+        if state_copy.assign_and_propagate(row, col, (sln_idx + 1) as i8) {
+            local.push((state_copy, job.clone()));
+            tasks_pushed += 1;
+        }
+    }
+    // We consumed one task (this one) and produced `tasks_pushed` more.
+    job.account_for_tasks(tasks_pushed - 1);
+}
+
+/*
+Parsing
+*/
+
+/// Parses a puzzle out of `input`. Accepts the common interchange forms: a bare
+/// 81-character string using '1'-'9' for givens and '.' or '0' for blanks, and
+/// the same string broken across multiple lines with `|`/`-`/`+` box-drawing
+/// separators and whitespace sprinkled in (those characters are simply skipped).
+/// Returns a descriptive error instead of panicking on malformed input.
+pub fn parse_board(input: &str) -> Result<State, String> {
+    let mut state = State {
+        unsolved_squares: 81,
+        board: [[Square {
+            solution: 0,
+            num_possible: 9,
+            possible: [true; 9],
+        }; 9]; 9],
+    };
+    let mut row = 0;
+    let mut col = 0;
+    for ch in input.chars() {
+        if row == 9 {
+            break;
+        }
+        let given = match ch {
+            '1'..='9' => Some(ch as i8 - '0' as i8),
+            '.' | '0' => None,
+            c if c.is_whitespace() || c == '|' || c == '-' || c == '+' => continue,
+            c => return Err(format!("unexpected character '{}' in puzzle input", c)),
+        };
+        if let Some(solution) = given {
+            // Propagation from an earlier given may have already solved this
+            // square (e.g. as a hidden single) before we reach its own given
+            // digit. Re-propagating would double-count it against
+            // `unsolved_squares`; just confirm it agrees with the given.
+            let already_solved = state.board[row][col].solution;
+            if already_solved == 0 {
+                if !state.assign_and_propagate(row, col, solution) {
+                    return Err("puzzle is unsolvable: givens conflict with each other".to_string());
+                }
+            } else if already_solved != solution {
+                return Err("puzzle is unsolvable: givens conflict with each other".to_string());
+            }
+        }
+        col += 1;
+        if col == 9 {
+            col = 0;
+            row += 1;
+        }
+    }
+    if row != 9 {
+        return Err(format!(
+            "expected 81 puzzle cells, found {}",
+            row * 9 + col
+        ));
+    }
+    return Ok(state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A complete, valid solved grid, serialized row-major. Used as a base for
+    // constructing puzzles that are already (almost) solved.
+    const SOLVED_GRID: &str =
+        "534678912672195348198342567859761423426853791713924856961537284287419635345286179";
+
+    fn blank_cell(grid: &str, row: usize, col: usize) -> String {
+        let mut chars: Vec<char> = grid.chars().collect();
+        chars[row * 9 + col] = '.';
+        return chars.into_iter().collect();
+    }
+
+    // A fully solved grid minus one cell has that cell's value forced the moment
+    // it's parsed, since every peer is already solved: the naked-single path
+    // alone (no branching search) is enough to finish the puzzle.
+    #[test]
+    fn naked_single_completes_an_almost_solved_puzzle() {
+        let puzzle = blank_cell(SOLVED_GRID, 0, 0);
+        let state = parse_board(&puzzle).expect("puzzle should parse and solve");
+        assert_eq!(state.unsolved_squares, 0);
+        assert_eq!(state.board[0][0].solution, 5);
+    }
+
+    // Builds an otherwise-empty board where value 1 has exactly one remaining
+    // candidate position in row 0 (cell (0, 0)), but (0, 0) itself still has
+    // every other value as a candidate too — so it's only forced by the
+    // hidden-single check, not the naked-single one.
+    fn board_with_hidden_single_at_origin() -> State {
+        let mut state = State {
+            unsolved_squares: 81,
+            board: [[Square {
+                solution: 0,
+                num_possible: 9,
+                possible: [true; 9],
+            }; 9]; 9],
+        };
+        for col in 1..9 {
+            state.board[0][col].possible[0] = false;
+            state.board[0][col].num_possible -= 1;
+        }
+        for row in 1..9 {
+            state.board[row][0].possible[0] = false;
+            state.board[row][0].num_possible -= 1;
+        }
+        for row in 0..3 {
+            for col in 0..3 {
+                if (row, col) != (0, 0) {
+                    state.board[row][col].possible[0] = false;
+                    state.board[row][col].num_possible -= 1;
+                }
+            }
+        }
+        return state;
+    }
+
+    #[test]
+    fn hidden_single_is_assigned_even_when_not_a_naked_single() {
+        let mut state = board_with_hidden_single_at_origin();
+        assert_eq!(state.board[0][0].num_possible, 9);
+        let mut worklist = Worklist::new();
+        assert!(state.assign_hidden_singles(0, 0, &mut worklist));
+        assert_eq!(state.board[0][0].solution, 1);
+    }
+
+    // Peers overlap heavily (a square's row, column, and box all share cells),
+    // so `enqueue_peers` alone pushes the same square many times per
+    // assignment; the worklist must still only hand each one back out once
+    // per pending period. This is a regression test for a bug where the
+    // fixpoint re-processed the same square repeatedly instead of once.
+    #[test]
+    fn worklist_deduplicates_pending_entries() {
+        let mut worklist = Worklist::new();
+        worklist.push(3, 4);
+        worklist.push(3, 4);
+        worklist.push(3, 4);
+        assert_eq!(worklist.pop(), Some((3, 4)));
+        assert_eq!(worklist.pop(), None);
+
+        // Once popped, the same square can be queued again.
+        worklist.push(3, 4);
+        assert_eq!(worklist.pop(), Some((3, 4)));
+    }
+
+    // Two squares with an equal number of remaining candidates should be
+    // broken by whichever has the smaller constraining unit (fewest unsolved
+    // peers in its row, column, or box), since branching there prunes the
+    // search tree more.
+    #[test]
+    fn most_constrained_square_breaks_ties_by_smallest_unit() {
+        let mut state = State {
+            unsolved_squares: 81,
+            board: [[Square {
+                solution: 0,
+                num_possible: 9,
+                possible: [true; 9],
+            }; 9]; 9],
+        };
+        // (0, 0) and (4, 4) tie on remaining candidates...
+        state.board[0][0].num_possible = 3;
+        state.board[4][4].num_possible = 3;
+        // ...but almost all of row 4 is already solved, so (4, 4)'s row unit
+        // is far smaller than (0, 0)'s wide-open row, column, and box.
+        for col in 0..9 {
+            if col != 4 {
+                state.board[4][col].solution = (col + 1) as i8;
+            }
+        }
+
+        assert_eq!(find_most_constrained_square(&state), Some((4, 4)));
+    }
+}
@@ -0,0 +1,152 @@
+// This crate consistently favors explicit `return` statements and a blank
+// `println!()` as a grid-row spacer; silence the corresponding lints rather
+// than fight the style.
+#![allow(clippy::needless_return, clippy::println_empty_string)]
+
+use std::io;
+use std::io::BufRead;
+
+use futures::StreamExt;
+
+use sudoku_rs::{parse_board, State, ThreadPool};
+
+fn main() {
+    // Get command line args, skipping argv[0]. The thread count is the one
+    // positional (non-flag) argument, so flags may come before or after it.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let num_threads = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .expect("Please specify a number of threads via command line arg, e.g. `./sudoku 2`")
+        .parse::<usize>()
+        .unwrap();
+    // We need at least one thread to do the work.
+    assert!(num_threads > 0, "{}", num_threads);
+    let format = if args.iter().any(|arg| arg == "--format=line") {
+        OutputFormat::Line
+    } else {
+        OutputFormat::Pretty
+    };
+
+    // Built once and reused for every puzzle, so `--batch` mode solves many
+    // puzzles back to back without spinning up a new set of threads each time.
+    let pool = ThreadPool::new(num_threads);
+
+    if args.iter().any(|arg| arg == "--batch") {
+        // Batch mode: one puzzle per line on stdin.
+        solve_each_line_from_stdin(&pool, format);
+    } else {
+        let state = read_single_puzzle_from_stdin();
+        parallel_solve(&pool, state, format);
+    }
+}
+
+/*
+I/O
+*/
+
+// Which serialization `print_solution` emits.
+#[derive(Copy, Clone)]
+enum OutputFormat {
+    // The ASCII-art grid `print_board` has always produced.
+    Pretty,
+    // A single 81-character line, blanks as '0' — the compact interchange
+    // format solutions can be piped into other tools in.
+    Line,
+}
+
+// Reads one puzzle from stdin, in any format `parse_board` accepts, and parses
+// it. Prints a descriptive error and exits instead of panicking on malformed
+// input.
+fn read_single_puzzle_from_stdin() -> State {
+    // Read all of stdin, not just one line, so multi-line grids parse too.
+    let mut input = String::new();
+    io::Read::read_to_string(&mut io::stdin(), &mut input)
+        .expect("failed to read puzzle from stdin");
+    return parse_board(&input).unwrap_or_else(|error| {
+        eprintln!("error: {}", error);
+        std::process::exit(1);
+    });
+}
+
+// Batch mode: solves every puzzle given one per line on stdin. A
+// malformed line is reported and skipped rather than aborting the whole batch.
+fn solve_each_line_from_stdin(pool: &ThreadPool, format: OutputFormat) {
+    for line in io::stdin().lock().lines() {
+        let line = line.expect("failed to read puzzle from stdin");
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_board(&line) {
+            Ok(state) => {
+                parallel_solve(pool, state, format);
+            }
+            Err(error) => eprintln!("error: {}", error),
+        }
+    }
+}
+
+// Searches `state` with `pool` and blocks until the first solution is found
+// (or the search is exhausted), printing it in `format`.
+// Returns whether a solution was found.
+fn parallel_solve(pool: &ThreadPool, state: State, format: OutputFormat) -> bool {
+    let (mut solutions, abort_handle) = pool.solve_stream(state);
+    let first_solution = futures::executor::block_on(solutions.next());
+    // We only need the first solution; tell every worker to stop searching.
+    abort_handle.abort();
+    match first_solution {
+        Some(solution) => {
+            print_solution(&solution, format);
+            return true;
+        }
+        None => return false,
+    }
+}
+
+// Prints `state` in the requested `format`.
+fn print_solution(state: &State, format: OutputFormat) {
+    match format {
+        OutputFormat::Pretty => print_board(state),
+        OutputFormat::Line => println!("{}", board_to_line(state)),
+    }
+}
+
+// Serializes the board as a single 81-character line, blanks as '0'.
+fn board_to_line(state: &State) -> String {
+    let mut line = String::with_capacity(81);
+    for row in state.board.iter() {
+        for square in row.iter() {
+            line.push_str(&square.solution.to_string());
+        }
+    }
+    return line;
+}
+
+fn print_board(state: &State) {
+    println!("unsolved_squares: {}", state.unsolved_squares);
+    let mut row_idx = 0;
+    state.board.iter().for_each(|row| {
+        let mut col_idx = 0;
+        row.iter().for_each(|col| {
+            if col_idx == 3 || col_idx == 6 {
+                print!(" |  ")
+            }
+            if col.solution > 0 {
+                print!("{}", col.solution);
+            } else {
+                print!("_");
+            }
+            if col_idx < 9 {
+                print!(" ");
+            }
+            col_idx += 1;
+        });
+        println!("");
+        if row_idx == 2 || row_idx == 5 {
+            println!("-------------------------");
+        } else {
+            println!("                         ");
+        }
+        row_idx += 1;
+    });
+}